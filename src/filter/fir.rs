@@ -1,7 +1,10 @@
 /// Basic implementation of convolution operation via FIR filter
+use std::f32::consts::PI;
+
 use arraydeque::{ArrayDeque, Wrapping};
 use generic_array::{ArrayLength, GenericArray};
 use itertools::izip;
+use crate::window::Window;
 use crate::RealBuffer;
 
 
@@ -59,6 +62,29 @@ impl<N: ArrayLength<f32>> FIRFilter<N> {
 }
 
 
+/// Designs a linear-phase lowpass FIR by windowing the ideal sinc impulse
+/// response `h[n] = 2*fc*sinc(2*fc*(n - (N-1)/2))`, where `fc` is the
+/// cutoff normalized to the sample rate (`0.0..0.5`), and multiplying by
+/// the supplied window. The returned coefficients are ready for
+/// [`FIRFilter::new`].
+pub fn design_lowpass(num_taps: usize, fc: f32, window: &Window) -> RealBuffer {
+    assert_eq!(num_taps, window.len());
+    let center = (num_taps - 1) as f32 / 2.0;
+    let mut taps = vec![0.0; num_taps];
+    for (n, (tap, w)) in izip!(taps.iter_mut(), window.samples.iter()).enumerate() {
+        let x = n as f32 - center;
+        // Ideal lowpass impulse response; the center tap is the limit of
+        // the sinc as x -> 0.
+        let ideal = if x == 0.0 {
+            2.0 * fc
+        } else {
+            (2.0 * PI * fc * x).sin() / (PI * x)
+        };
+        *tap = ideal * *w;
+    }
+    taps
+}
+
 /// ------------------------------------------------------------------------------------------------
 /// Module unit tests
 /// ------------------------------------------------------------------------------------------------
@@ -68,6 +94,18 @@ mod tests {
     use crate::window;
     use generic_array::typenum::{U5};
 
+    #[test]
+    fn test_design_lowpass_is_linear_phase() {
+        // A windowed-sinc lowpass must be symmetric (linear phase) and
+        // have roughly unity gain at DC.
+        let taps = design_lowpass(21, 0.25, &window::hamming(21));
+        for i in 0..taps.len() / 2 {
+            assert!((taps[i] - taps[taps.len() - 1 - i]).abs() < 1e-6);
+        }
+        let dc_gain: f32 = taps.iter().sum();
+        assert!((dc_gain - 1.0).abs() < 0.05);
+    }
+
     #[test]
     fn test_fir_convolution() {
         // Test our FIR filter, which performs a discrete convolution
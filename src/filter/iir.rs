@@ -1,8 +1,11 @@
 /// Basic implementations of common discrete filters
+use std::f32::consts::PI;
+
 use arraydeque::{ArrayDeque, Wrapping};
 use generic_array::typenum::U3;
 use generic_array::{ArrayLength, GenericArray};
 use itertools::izip;
+use num_complex::Complex32;
 
 use super::Filter;
 
@@ -13,6 +16,10 @@ pub struct IIRFilter<N: ArrayLength<f32>> {
     y: ArrayDeque<GenericArray<f32, N>, Wrapping>,
     b: GenericArray<f32, N>,
     a: GenericArray<f32, N>,
+    /// Optional output saturation bounds. When set, the computed output
+    /// is clamped before being stored in the feedback history, which also
+    /// prevents an integrator from winding up past the limits.
+    limits: Option<(f32, f32)>,
 }
 
 /// A biquad IIR filter common for second-order section
@@ -55,8 +62,317 @@ impl<N: ArrayLength<f32>> IIRFilter<N> {
             y,
             b: b_arr,
             a: a_arr,
+            limits: None,
+        }
+    }
+
+    /// Enables output saturation to `[min, max]`. The clamp is applied
+    /// before the output is pushed into the feedback history, so the
+    /// stored state reflects the saturated value. This is opt-in; a
+    /// filter built with [`IIRFilter::new`] is unbounded.
+    pub fn with_limits(mut self, min: f32, max: f32) -> IIRFilter<N> {
+        self.limits = Some((min, max));
+        self
+    }
+}
+
+/// Second-order section designers following the Audio EQ Cookbook
+/// recurrences (Robert Bristow-Johnson). Each constructor builds the
+/// `[b0, b1, b2]` / `[a0, a1, a2]` arrays and hands them to
+/// `IIRFilter::new`, which already normalizes by `a[0]` and negates the
+/// feedback taps, so callers no longer need to paste coefficients from
+/// SciPy as the module tests do.
+impl IIRFilter<U3> {
+    /// Shared intermediate terms for the cookbook formulas, with
+    /// `w0 = 2*PI*f0/fs`: returns `(cos(w0), alpha)` where
+    /// `alpha = sin(w0) / (2*Q)`.
+    fn cookbook_terms(fs: f32, f0: f32, q: f32) -> (f32, f32) {
+        let w0 = 2.0 * PI * f0 / fs;
+        let cos_w0 = w0.cos();
+        let alpha = w0.sin() / (2.0 * q);
+        (cos_w0, alpha)
+    }
+
+    /// Low-pass biquad for center frequency `f0` at sample rate `fs`.
+    pub fn lowpass(fs: f32, f0: f32, q: f32) -> BiquadFilter {
+        let (cos_w0, alpha) = Self::cookbook_terms(fs, f0, q);
+        let b = [(1.0 - cos_w0) / 2.0, 1.0 - cos_w0, (1.0 - cos_w0) / 2.0];
+        let a = [1.0 + alpha, -2.0 * cos_w0, 1.0 - alpha];
+        IIRFilter::new(&b, &a)
+    }
+
+    /// High-pass biquad for center frequency `f0` at sample rate `fs`.
+    pub fn highpass(fs: f32, f0: f32, q: f32) -> BiquadFilter {
+        let (cos_w0, alpha) = Self::cookbook_terms(fs, f0, q);
+        let b = [(1.0 + cos_w0) / 2.0, -(1.0 + cos_w0), (1.0 + cos_w0) / 2.0];
+        let a = [1.0 + alpha, -2.0 * cos_w0, 1.0 - alpha];
+        IIRFilter::new(&b, &a)
+    }
+
+    /// Constant 0 dB peak-gain band-pass biquad (peak gain = 1).
+    pub fn bandpass(fs: f32, f0: f32, q: f32) -> BiquadFilter {
+        let (cos_w0, alpha) = Self::cookbook_terms(fs, f0, q);
+        let b = [alpha, 0.0, -alpha];
+        let a = [1.0 + alpha, -2.0 * cos_w0, 1.0 - alpha];
+        IIRFilter::new(&b, &a)
+    }
+
+    /// Notch (band-reject) biquad.
+    pub fn notch(fs: f32, f0: f32, q: f32) -> BiquadFilter {
+        let (cos_w0, alpha) = Self::cookbook_terms(fs, f0, q);
+        let b = [1.0, -2.0 * cos_w0, 1.0];
+        let a = [1.0 + alpha, -2.0 * cos_w0, 1.0 - alpha];
+        IIRFilter::new(&b, &a)
+    }
+
+    /// Peaking EQ biquad with `gain_db` of boost or cut at `f0`.
+    pub fn peaking(fs: f32, f0: f32, q: f32, gain_db: f32) -> BiquadFilter {
+        let (cos_w0, alpha) = Self::cookbook_terms(fs, f0, q);
+        let amp = f32::powf(10.0, gain_db / 40.0);
+        let b = [1.0 + alpha * amp, -2.0 * cos_w0, 1.0 - alpha * amp];
+        let a = [1.0 + alpha / amp, -2.0 * cos_w0, 1.0 - alpha / amp];
+        IIRFilter::new(&b, &a)
+    }
+
+    /// Low-shelf biquad with `gain_db` applied below `f0`.
+    pub fn lowshelf(fs: f32, f0: f32, q: f32, gain_db: f32) -> BiquadFilter {
+        let (cos_w0, alpha) = Self::cookbook_terms(fs, f0, q);
+        let amp = f32::powf(10.0, gain_db / 40.0);
+        let two_sqrt_a_alpha = 2.0 * amp.sqrt() * alpha;
+        let b = [
+            amp * ((amp + 1.0) - (amp - 1.0) * cos_w0 + two_sqrt_a_alpha),
+            2.0 * amp * ((amp - 1.0) - (amp + 1.0) * cos_w0),
+            amp * ((amp + 1.0) - (amp - 1.0) * cos_w0 - two_sqrt_a_alpha),
+        ];
+        let a = [
+            (amp + 1.0) + (amp - 1.0) * cos_w0 + two_sqrt_a_alpha,
+            -2.0 * ((amp - 1.0) + (amp + 1.0) * cos_w0),
+            (amp + 1.0) + (amp - 1.0) * cos_w0 - two_sqrt_a_alpha,
+        ];
+        IIRFilter::new(&b, &a)
+    }
+
+    /// High-shelf biquad with `gain_db` applied above `f0`.
+    pub fn highshelf(fs: f32, f0: f32, q: f32, gain_db: f32) -> BiquadFilter {
+        let (cos_w0, alpha) = Self::cookbook_terms(fs, f0, q);
+        let amp = f32::powf(10.0, gain_db / 40.0);
+        let two_sqrt_a_alpha = 2.0 * amp.sqrt() * alpha;
+        let b = [
+            amp * ((amp + 1.0) + (amp - 1.0) * cos_w0 + two_sqrt_a_alpha),
+            -2.0 * amp * ((amp - 1.0) + (amp + 1.0) * cos_w0),
+            amp * ((amp + 1.0) + (amp - 1.0) * cos_w0 - two_sqrt_a_alpha),
+        ];
+        let a = [
+            (amp + 1.0) - (amp - 1.0) * cos_w0 + two_sqrt_a_alpha,
+            2.0 * ((amp - 1.0) - (amp + 1.0) * cos_w0),
+            (amp + 1.0) - (amp - 1.0) * cos_w0 - two_sqrt_a_alpha,
+        ];
+        IIRFilter::new(&b, &a)
+    }
+}
+
+/// Builder for a discrete PID controller realized as a
+/// [`BiquadFilter`]. Second-order IIR sections are a common way to run a
+/// proportional/integral/derivative loop: the integrator contributes the
+/// pole at `a = [1, -1, 0]` and the derivative is taken as a backward
+/// difference. Optional output limits saturate the actuator command and,
+/// because the clamp feeds back through the filter state, prevent
+/// integrator wind-up.
+#[derive(Clone, Copy, Debug)]
+pub struct Pid {
+    kp: f32,
+    ki: f32,
+    kd: f32,
+    t: f32,
+    limits: Option<(f32, f32)>,
+}
+
+impl Pid {
+    /// Returns a new PID builder for gains `kp`/`ki`/`kd` at sample
+    /// period `t`.
+    pub fn new(kp: f32, ki: f32, kd: f32, t: f32) -> Pid {
+        Pid {
+            kp,
+            ki,
+            kd,
+            t,
+            limits: None,
+        }
+    }
+
+    /// Clamps the controller output to `[min, max]` with anti-windup.
+    pub fn with_limits(mut self, min: f32, max: f32) -> Pid {
+        self.limits = Some((min, max));
+        self
+    }
+
+    /// Builds the controller as a biquad. The discrete PID transfer
+    /// function over the common integrator denominator `1 - z^-1`
+    /// expands to `b0 = kp + ki*T + kd/T`, `b1 = -(kp + 2*kd/T)`,
+    /// `b2 = kd/T` with `a = [1, -1, 0]`.
+    pub fn build(&self) -> BiquadFilter {
+        let kd_t = self.kd / self.t;
+        let b = [
+            self.kp + self.ki * self.t + kd_t,
+            -(self.kp + 2.0 * kd_t),
+            kd_t,
+        ];
+        let a = [1.0, -1.0, 0.0];
+        let filter = IIRFilter::new(&b, &a);
+        match self.limits {
+            Some((min, max)) => filter.with_limits(min, max),
+            None => filter,
+        }
+    }
+}
+
+/// An IIR filter realized as a cascade (series) of biquad second-order
+/// sections (SOS). This is the numerically friendly way to run a
+/// high-order design: rather than a single long tap vector, the response
+/// is split into independent `BiquadFilter` stages that the sample is
+/// threaded through in series.
+///
+/// The `butterworth_*` constructors build the sections from the analog
+/// Butterworth zero-pole-gain prototype via the bilinear transform, so a
+/// design is specified by order and cutoff instead of precomputed taps.
+#[derive(Clone, Debug)]
+pub struct SOSFilter {
+    sections: Vec<BiquadFilter>,
+}
+
+/// Designer alias emphasizing the prototype used by the constructors.
+pub type ButterworthFilter = SOSFilter;
+
+impl SOSFilter {
+    /// Returns a cascade from a list of `[b0, b1, b2]` / `[a0, a1, a2]`
+    /// section coefficients.
+    pub fn new(sections: &[([f32; 3], [f32; 3])]) -> SOSFilter {
+        let sections = sections
+            .iter()
+            .map(|(b, a)| IIRFilter::new(b, a))
+            .collect();
+        SOSFilter { sections }
+    }
+
+    /// Butterworth low-pass of the given `order` and cutoff `fc`.
+    pub fn butterworth_lowpass(order: usize, fs: f32, fc: f32) -> SOSFilter {
+        SOSFilter {
+            sections: butterworth_sections(order, fs, fc, Band::Low),
+        }
+    }
+
+    /// Butterworth high-pass of the given `order` and cutoff `fc`.
+    pub fn butterworth_highpass(order: usize, fs: f32, fc: f32) -> SOSFilter {
+        SOSFilter {
+            sections: butterworth_sections(order, fs, fc, Band::High),
         }
     }
+
+    /// Butterworth band-pass spanning `[f_low, f_high]`, realized as a
+    /// high-pass cascade followed by a low-pass cascade, each of the
+    /// given `order`.
+    pub fn butterworth_bandpass(order: usize, fs: f32, f_low: f32, f_high: f32) -> SOSFilter {
+        let mut sections = butterworth_sections(order, fs, f_low, Band::High);
+        sections.extend(butterworth_sections(order, fs, f_high, Band::Low));
+        SOSFilter { sections }
+    }
+}
+
+/// Which band an analog Butterworth prototype section realizes.
+#[derive(Clone, Copy)]
+enum Band {
+    Low,
+    High,
+}
+
+/// Builds the bilinear-transformed biquad sections for a Butterworth
+/// prototype of the requested order. Conjugate poles pair into
+/// second-order sections; an odd order additionally emits one
+/// first-order section carried in a biquad with a zero third tap.
+fn butterworth_sections(order: usize, fs: f32, fc: f32, band: Band) -> Vec<BiquadFilter> {
+    assert!(order >= 1);
+
+    // Prewarp the cutoff so the bilinear transform lands the analog
+    // cutoff on the intended digital frequency.
+    let wc = 2.0 * fs * (PI * fc / fs).tan();
+    let c = 2.0 * fs;
+    let n = order;
+
+    let mut sections = Vec::new();
+
+    // Conjugate pole pairs: take the poles in the upper half-plane.
+    for k in 0..(n / 2) {
+        let theta = PI * (2.0 * k as f32 + 1.0) / (2.0 * n as f32) + PI / 2.0;
+        let s_k = Complex32::new(theta.cos(), theta.sin());
+
+        // Frequency-scaled analog pole for the chosen band. For the
+        // high-pass the low-pass-to-high-pass map sends s -> wc/s, which
+        // for a unit-circle prototype pole is wc * conj(s_k).
+        let (num, pole) = match band {
+            Band::Low => ([0.0, 0.0, wc * wc], wc * s_k),
+            Band::High => ([1.0, 0.0, 0.0], wc * s_k.conj()),
+        };
+        // Denominator of the conjugate pair: s^2 - 2 Re(p) s + |p|^2.
+        let den = [1.0, -2.0 * pole.re, pole.norm_sqr()];
+        sections.push(bilinear_biquad(num, den, c));
+    }
+
+    // Odd order leaves a single real pole at s = -wc as a first-order
+    // section.
+    if n % 2 == 1 {
+        let (num, den) = match band {
+            // wc / (s + wc)
+            Band::Low => ([0.0, 0.0, wc], [0.0, 1.0, wc]),
+            // s / (s + wc)
+            Band::High => ([0.0, 1.0, 0.0], [0.0, 1.0, wc]),
+        };
+        sections.push(bilinear_first_order(num, den, c));
+    }
+
+    sections
+}
+
+/// Applies the bilinear substitution `s = c (z-1)/(z+1)` to a
+/// second-order analog section `(B0 s^2 + B1 s + B2)/(A0 s^2 + A1 s + A2)`
+/// and returns the resulting biquad.
+fn bilinear_biquad(num: [f32; 3], den: [f32; 3], c: f32) -> BiquadFilter {
+    let [b0, b1, b2] = num;
+    let [a0, a1, a2] = den;
+    let c2 = c * c;
+    let b = [
+        b0 * c2 + b1 * c + b2,
+        -2.0 * b0 * c2 + 2.0 * b2,
+        b0 * c2 - b1 * c + b2,
+    ];
+    let a = [
+        a0 * c2 + a1 * c + a2,
+        -2.0 * a0 * c2 + 2.0 * a2,
+        a0 * c2 - a1 * c + a2,
+    ];
+    IIRFilter::new(&b, &a)
+}
+
+/// Bilinear transform of a first-order analog section
+/// `(B1 s + B0)/(A1 s + A0)`, emitted as a biquad with a zero third tap.
+/// The coefficient arrays are laid out `[_, s-term, const]` to mirror the
+/// second-order layout.
+fn bilinear_first_order(num: [f32; 3], den: [f32; 3], c: f32) -> BiquadFilter {
+    let b1 = num[1];
+    let b0 = num[2];
+    let a1 = den[1];
+    let a0 = den[2];
+    let b = [b1 * c + b0, b0 - b1 * c, 0.0];
+    let a = [a1 * c + a0, a0 - a1 * c, 0.0];
+    IIRFilter::new(&b, &a)
+}
+
+impl Filter for SOSFilter {
+    /// Threads one sample through every section in series.
+    fn process_one(&mut self, in_samp: f32) -> f32 {
+        self.sections
+            .iter_mut()
+            .fold(in_samp, |samp, section| section.process_one(samp))
+    }
 }
 
 impl<N: ArrayLength<f32>> Filter for IIRFilter<N> {
@@ -78,12 +394,92 @@ impl<N: ArrayLength<f32>> Filter for IIRFilter<N> {
         }
         sum /= self.a[0];
 
+        // Saturate the output before storing it, so a bounded filter's
+        // feedback state cannot wind up past the actuator range.
+        if let Some((min, max)) = self.limits {
+            sum = sum.max(min).min(max);
+        }
+
         // Update y and return the result
         self.y.push_front(sum);
         sum
     }
 }
 
+/// A fixed-point biquad IIR for targets without an FPU.
+///
+/// Coefficients are stored as signed Q2.30 fixed-point and the per-sample
+/// multiply-accumulate runs in a widened `i64` accumulator, so the filter
+/// mirrors the `f32` [`IIRFilter`] path without any floating-point math.
+/// The coefficient layout `[b0, b1, b2, a1, a2]` follows the stabilizer
+/// design; the feedback taps `a1`/`a2` are stored pre-negated just as
+/// [`IIRFilter::new`] negates them.
+#[derive(Clone, Debug)]
+pub struct IntIIR {
+    x: ArrayDeque<[i32; 3], Wrapping>,
+    y: ArrayDeque<[i32; 3], Wrapping>,
+    ba: [i32; 5],
+}
+
+impl IntIIR {
+    /// Number of fractional bits in the Q2.30 coefficient representation;
+    /// also the right-shift applied to the `i64` accumulator.
+    pub const SHIFT: u32 = 30;
+
+    /// Returns a new fixed-point biquad from Q2.30 coefficients laid out
+    /// `[b0, b1, b2, a1, a2]`, with the feedback taps already negated.
+    pub fn new(ba: [i32; 5]) -> IntIIR {
+        let mut x: ArrayDeque<[i32; 3], Wrapping> = ArrayDeque::new();
+        let mut y: ArrayDeque<[i32; 3], Wrapping> = ArrayDeque::new();
+        for _ in 0..x.capacity() {
+            x.push_front(0);
+        }
+        for _ in 0..y.capacity() {
+            y.push_front(0);
+        }
+        IntIIR { x, y, ba }
+    }
+
+    /// Quantizes a float biquad (`[b0, b1, b2]` / `[a0, a1, a2]`, e.g. from
+    /// the cookbook constructors) into the Q2.30 layout expected by
+    /// [`IntIIR::new`]. Coefficients are normalized by `a0` and the
+    /// feedback taps are negated so the fixed-point path matches the
+    /// `f32` recurrence.
+    pub fn quantize(b: &[f32; 3], a: &[f32; 3]) -> [i32; 5] {
+        let scale = (1_i64 << Self::SHIFT) as f32;
+        let to_fixed = |v: f32| (v * scale).round() as i32;
+        [
+            to_fixed(b[0] / a[0]),
+            to_fixed(b[1] / a[0]),
+            to_fixed(b[2] / a[0]),
+            to_fixed(-a[1] / a[0]),
+            to_fixed(-a[2] / a[0]),
+        ]
+    }
+
+    /// Process one fixed-point sample and return one output sample.
+    pub fn process_one(&mut self, in_samp: i32) -> i32 {
+        // Shift in the new input, drop the oldest history.
+        self.x.pop_back();
+        self.x.push_front(in_samp);
+        self.y.pop_back();
+
+        // Round-half-up bias, then accumulate the feed-forward and
+        // (pre-negated) feedback taps in a widened accumulator.
+        let mut acc = 1_i64 << (Self::SHIFT - 1);
+        for (xi, bi) in izip!(self.x.iter(), self.ba[..3].iter()) {
+            acc += *xi as i64 * *bi as i64;
+        }
+        for (yi, ai) in izip!(self.y.iter(), self.ba[3..].iter()) {
+            acc += *yi as i64 * *ai as i64;
+        }
+        let out = (acc >> Self::SHIFT) as i32;
+
+        self.y.push_front(out);
+        out
+    }
+}
+
 /// ------------------------------------------------------------------------------------------------
 /// Module unit tests
 /// ------------------------------------------------------------------------------------------------
@@ -129,6 +525,122 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cookbook_lowpass_response() {
+        // The cookbook low-pass should pass DC with unity gain and
+        // strongly attenuate a signal near Nyquist. Drive it with a
+        // constant (DC) and a Nyquist-alternating sequence and compare
+        // the steady-state magnitudes.
+        let fs = 48_000.0;
+        let f0 = 1_000.0;
+        let q = std::f32::consts::FRAC_1_SQRT_2;
+
+        let mut dc: BiquadFilter = IIRFilter::lowpass(fs, f0, q);
+        let mut last = 0.0;
+        for _ in 0..256 {
+            last = dc.process_one(1.0);
+        }
+        assert_approx_eq!(last, 1.0, 1e-3);
+
+        let mut ny: BiquadFilter = IIRFilter::lowpass(fs, f0, q);
+        let mut mag = 0.0;
+        for i in 0..256 {
+            let out = ny.process_one(if i % 2 == 0 { 1.0 } else { -1.0 });
+            mag = out.abs();
+        }
+        assert!(mag < 0.01);
+    }
+
+    #[test]
+    fn test_butterworth_sos_lowpass() {
+        // A 6th-order Butterworth low-pass cascade should pass DC at
+        // unity gain and reject a Nyquist-alternating sequence.
+        let fs = 48_000.0;
+        let mut lpf = SOSFilter::butterworth_lowpass(6, fs, 1_000.0);
+        let mut dc = 0.0;
+        for _ in 0..512 {
+            dc = lpf.process_one(1.0);
+        }
+        assert_approx_eq!(dc, 1.0, 1e-2);
+
+        let mut ny = SOSFilter::butterworth_lowpass(6, fs, 1_000.0);
+        let mut mag = 0.0;
+        for i in 0..512 {
+            mag = ny.process_one(if i % 2 == 0 { 1.0 } else { -1.0 }).abs();
+        }
+        assert!(mag < 1e-3);
+    }
+
+    #[test]
+    fn test_butterworth_sos_odd_order_highpass() {
+        // An odd-order high-pass emits a first-order trailing section; it
+        // must block DC while passing a Nyquist-alternating sequence.
+        let fs = 48_000.0;
+        let mut hpf = SOSFilter::butterworth_highpass(5, fs, 1_000.0);
+        let mut dc = 0.0;
+        for _ in 0..512 {
+            dc = hpf.process_one(1.0);
+        }
+        assert_approx_eq!(dc, 0.0, 1e-2);
+
+        let mut ny = SOSFilter::butterworth_highpass(5, fs, 1_000.0);
+        let mut mag = 0.0;
+        for i in 0..512 {
+            mag = ny.process_one(if i % 2 == 0 { 1.0 } else { -1.0 }).abs();
+        }
+        assert_approx_eq!(mag, 1.0, 1e-2);
+    }
+
+    #[test]
+    fn test_int_iir_matches_float_dc_gain() {
+        // Quantize a cookbook low-pass and confirm the fixed-point path
+        // converges to unity DC gain (output tracks the constant input)
+        // to within the Q2.30 rounding noise.
+        let fs = 48_000.0;
+        let f0 = 1_000.0;
+        let q = std::f32::consts::FRAC_1_SQRT_2;
+
+        let float = IIRFilter::<U3>::lowpass(fs, f0, q);
+        let ba = IntIIR::quantize(
+            &[float.b[0], float.b[1], float.b[2]],
+            // `a` is stored with the feedback taps pre-negated, so undo
+            // that to recover the raw coefficients before re-quantizing.
+            &[float.a[0], -float.a[1], -float.a[2]],
+        );
+        let mut fixed = IntIIR::new(ba);
+
+        let input = 1_000_000;
+        let mut out = 0;
+        for _ in 0..512 {
+            out = fixed.process_one(input);
+        }
+        assert!((out - input).abs() < input / 1000);
+    }
+
+    #[test]
+    fn test_pid_anti_windup_clamps_output() {
+        // A pure integrator driven by a constant error would ramp without
+        // bound; with output limits the command must saturate at the
+        // ceiling instead of winding up.
+        let mut pid = Pid::new(0.0, 1.0, 0.0, 1.0).with_limits(-5.0, 5.0).build();
+        let mut out = 0.0;
+        for _ in 0..100 {
+            out = pid.process_one(1.0);
+        }
+        assert_approx_eq!(out, 5.0, 1e-6);
+    }
+
+    #[test]
+    fn test_iir_limits_are_opt_in() {
+        // Without limits the same integrator keeps accumulating.
+        let mut pid = Pid::new(0.0, 1.0, 0.0, 1.0).build();
+        let mut out = 0.0;
+        for _ in 0..100 {
+            out = pid.process_one(1.0);
+        }
+        assert!(out > 50.0);
+    }
+
     #[test]
     fn test_iir_lowpass_real() {
         // Test our IIR filter implementation by filtering out from
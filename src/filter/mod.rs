@@ -5,6 +5,8 @@ use crate::RealBuffer;
 
 pub mod fir;
 pub mod iir;
+pub mod multirate;
+pub mod svf;
 
 /// Defines shared behavior for all filter implementations.
 pub trait Filter {
@@ -0,0 +1,177 @@
+/// Half-band FIR decimation and interpolation for 2x sample-rate conversion
+use std::f32::consts::PI;
+
+use crate::RealBuffer;
+
+/// Generates the coefficients of a half-band lowpass FIR from a
+/// windowed-sinc prototype with cutoff at a quarter of the sample rate.
+///
+/// A half-band filter has every tap an even distance from the center
+/// equal to zero (the center tap itself is `0.5`), which is exactly the sparsity the
+/// decimator and interpolator exploit. `num_taps` controls the transition
+/// bandwidth — more taps give a sharper roll-off — and is rounded up to
+/// the nearest `4k + 3` so the half-band symmetry holds exactly.
+pub fn halfband(num_taps: usize) -> RealBuffer {
+    // Round up to the nearest length of the form 4k+3 so the even taps
+    // land on the zeros of the sinc and the filter is symmetric.
+    let n = {
+        let mut n = num_taps | 1;
+        while (n - 3) % 4 != 0 {
+            n += 2;
+        }
+        n
+    };
+    let m = (n - 1) as f32 / 2.0;
+
+    let mut taps = vec![0.0; n];
+    for (i, tap) in taps.iter_mut().enumerate() {
+        let k = i as f32 - m;
+        // Ideal half-band impulse response: 0.5 * sinc(k/2).
+        let ideal = if k == 0.0 {
+            0.5
+        } else if k % 2.0 == 0.0 {
+            // Zeros of the sinc, stored as exact zeros so the convolution
+            // skips them.
+            0.0
+        } else {
+            0.5 * (PI * k / 2.0).sin() / (PI * k / 2.0)
+        };
+        // Hamming window to tame the truncation ripple.
+        let w = 0.54 - 0.46 * (2.0 * PI * i as f32 / (n as f32 - 1.0)).cos();
+        *tap = ideal * w;
+    }
+    taps
+}
+
+/// Folded, sparse convolution for a linear-phase half-band filter. The
+/// `b[i] == b[N-1-i]` symmetry lets the symmetric history pairs be summed
+/// before multiplying, and the half-band structure (every even-offset tap
+/// is zero) lets those taps be skipped entirely.
+fn halfband_convolve(taps: &[f32], history: &[f32]) -> f32 {
+    let n = taps.len();
+    let mut sum = 0.0;
+    for i in (0..n / 2).filter(|&i| taps[i] != 0.0) {
+        sum += taps[i] * (history[i] + history[n - 1 - i]);
+    }
+    if n % 2 == 1 {
+        sum += taps[n / 2] * history[n / 2];
+    }
+    sum
+}
+
+/// A 2x decimator: lowpass filters the input and keeps every second
+/// sample. The half-band taps let each output be computed from a folded
+/// sum of symmetric pairs, and an output is only produced on every second
+/// input sample.
+#[derive(Clone, Debug)]
+pub struct Decimator {
+    taps: RealBuffer,
+    history: RealBuffer,
+    phase: usize,
+}
+
+impl Decimator {
+    /// Returns a new decimator from half-band coefficients, e.g. those
+    /// produced by [`halfband`].
+    pub fn new(taps: &[f32]) -> Decimator {
+        Decimator {
+            taps: taps.to_vec(),
+            history: vec![0.0; taps.len()],
+            phase: 0,
+        }
+    }
+
+    /// Decimates `input` by two. An output is emitted on every second
+    /// input sample counted across calls, so a call returns
+    /// `input.len() / 2` samples, plus one when `input` has odd length and
+    /// an odd number of samples went through earlier calls.
+    pub fn process(&mut self, input: &RealBuffer) -> RealBuffer {
+        let mut output = Vec::with_capacity(input.len() / 2);
+        for &x in input.iter() {
+            // Shift the newest sample into the delay line.
+            self.history.rotate_right(1);
+            self.history[0] = x;
+
+            // Emit an output on every second input sample.
+            self.phase ^= 1;
+            if self.phase == 0 {
+                output.push(halfband_convolve(&self.taps, &self.history));
+            }
+        }
+        output
+    }
+}
+
+/// A 2x interpolator: inserts a zero sample between inputs, runs the same
+/// symmetric half-band taps, and scales by two to preserve passband gain.
+#[derive(Clone, Debug)]
+pub struct Interpolator {
+    taps: RealBuffer,
+    history: RealBuffer,
+}
+
+impl Interpolator {
+    /// Returns a new interpolator from half-band coefficients, e.g. those
+    /// produced by [`halfband`].
+    pub fn new(taps: &[f32]) -> Interpolator {
+        Interpolator {
+            taps: taps.to_vec(),
+            history: vec![0.0; taps.len()],
+        }
+    }
+
+    /// Interpolates `input` by two, returning `input.len() * 2` samples.
+    pub fn process(&mut self, input: &RealBuffer) -> RealBuffer {
+        let mut output = Vec::with_capacity(input.len() * 2);
+        for &x in input.iter() {
+            // Zero-stuff: one real sample followed by an inserted zero.
+            for &upsampled in &[x, 0.0] {
+                self.history.rotate_right(1);
+                self.history[0] = upsampled;
+                output.push(2.0 * halfband_convolve(&self.taps, &self.history));
+            }
+        }
+        output
+    }
+}
+
+/// ------------------------------------------------------------------------------------------------
+/// Module unit tests
+/// ------------------------------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_halfband_is_half_band() {
+        // Every even tap away from the center must be zero, and the
+        // center tap must be 0.5.
+        let taps = halfband(11);
+        let center = taps.len() / 2;
+        assert_approx_eq!(taps[center], 0.5, 1e-6);
+        for (i, tap) in taps.iter().enumerate() {
+            if i != center && (i as i32 - center as i32) % 2 == 0 {
+                assert_approx_eq!(*tap, 0.0, 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decimator_output_length() {
+        let taps = halfband(11);
+        let mut dec = Decimator::new(&taps);
+        let input = vec![1.0; 64];
+        let out = dec.process(&input);
+        assert_eq!(out.len(), input.len() / 2);
+    }
+
+    #[test]
+    fn test_interpolator_output_length() {
+        let taps = halfband(11);
+        let mut interp = Interpolator::new(&taps);
+        let input = vec![1.0; 32];
+        let out = interp.process(&input);
+        assert_eq!(out.len(), input.len() * 2);
+    }
+}
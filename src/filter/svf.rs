@@ -0,0 +1,130 @@
+/// Zero-delay-feedback (TPT) state variable filter
+use std::f32::consts::PI;
+
+use super::Filter;
+
+/// The four simultaneous responses produced by a single state variable
+/// filter pass.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SVFOutputs {
+    pub lowpass: f32,
+    pub highpass: f32,
+    pub bandpass: f32,
+    pub notch: f32,
+}
+
+/// A topology-preserving transform (TPT) state variable filter.
+///
+/// Unlike a biquad, a single pass yields lowpass, highpass, bandpass and
+/// notch responses from the same pair of integrator states, which makes
+/// it convenient for resonant sweeps and crossover design. The cutoff
+/// `fc` and resonance `Q` can be retuned live without disturbing the
+/// stored state.
+#[derive(Clone, Debug)]
+pub struct StateVariableFilter {
+    fs: f32,
+    g: f32,
+    k: f32,
+    ic1eq: f32,
+    ic2eq: f32,
+}
+
+impl StateVariableFilter {
+    /// Returns a new state variable filter tuned to cutoff `fc` and
+    /// resonance `q` at sample rate `fs`.
+    pub fn new(fs: f32, fc: f32, q: f32) -> StateVariableFilter {
+        let mut filter = StateVariableFilter {
+            fs,
+            g: 0.0,
+            k: 0.0,
+            ic1eq: 0.0,
+            ic2eq: 0.0,
+        };
+        filter.set_params(fc, q);
+        filter
+    }
+
+    /// Retunes the cutoff and resonance, leaving the integrator state
+    /// untouched so the filter can be swept while running.
+    pub fn set_params(&mut self, fc: f32, q: f32) {
+        self.g = (PI * fc / self.fs).tan();
+        self.k = 1.0 / q;
+    }
+
+    /// Clears the integrator state.
+    pub fn reset(&mut self) {
+        self.ic1eq = 0.0;
+        self.ic2eq = 0.0;
+    }
+
+    /// Process one sample and return all four responses at once.
+    pub fn process_all(&mut self, in_samp: f32) -> SVFOutputs {
+        let v0 = in_samp;
+        let v3 = v0 - self.ic2eq;
+        let v1 = (self.ic1eq + self.g * v3) / (1.0 + self.g * (self.g + self.k));
+        let v2 = self.ic2eq + self.g * v1;
+        self.ic1eq = 2.0 * v1 - self.ic1eq;
+        self.ic2eq = 2.0 * v2 - self.ic2eq;
+
+        SVFOutputs {
+            lowpass: v2,
+            bandpass: v1,
+            highpass: v0 - self.k * v1 - v2,
+            notch: v0 - self.k * v1,
+        }
+    }
+}
+
+impl Filter for StateVariableFilter {
+    /// Process one sample, returning the lowpass response. Use
+    /// [`process_all`](StateVariableFilter::process_all) to access the
+    /// other outputs.
+    fn process_one(&mut self, in_samp: f32) -> f32 {
+        self.process_all(in_samp).lowpass
+    }
+}
+
+/// ------------------------------------------------------------------------------------------------
+/// Module unit tests
+/// ------------------------------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_svf_outputs_sum_to_input() {
+        // For the TPT state variable filter the lowpass, highpass and
+        // k-scaled bandpass outputs reconstruct the input sample by
+        // sample, regardless of the stored state.
+        let mut svf = StateVariableFilter::new(48_000.0, 1_000.0, 0.707);
+        for i in 0..128 {
+            let x = (i as f32 * 0.1).sin();
+            let out = svf.process_all(x);
+            assert_approx_eq!(out.lowpass + out.highpass + svf_bandpass_term(&svf, &out), x, 1e-4);
+        }
+    }
+
+    /// Helper reconstructing `k * bandpass` for the sum identity.
+    fn svf_bandpass_term(svf: &StateVariableFilter, out: &SVFOutputs) -> f32 {
+        svf.k * out.bandpass
+    }
+
+    #[test]
+    fn test_svf_dc_is_lowpass() {
+        // At DC the response should settle to unity lowpass with no
+        // highpass content.
+        let mut svf = StateVariableFilter::new(48_000.0, 1_000.0, 0.707);
+        let mut out = SVFOutputs {
+            lowpass: 0.0,
+            highpass: 0.0,
+            bandpass: 0.0,
+            notch: 0.0,
+        };
+        for _ in 0..4096 {
+            out = svf.process_all(1.0);
+        }
+        assert_approx_eq!(out.lowpass, 1.0, 1e-3);
+        assert_approx_eq!(out.highpass, 0.0, 1e-3);
+    }
+}
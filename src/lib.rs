@@ -11,3 +11,4 @@ pub mod vectors;
 pub mod signal;
 pub mod freq;
 pub mod time;
+pub mod window;
@@ -0,0 +1,99 @@
+//! Window functions for spectral analysis and FIR filter design.
+
+use std::f32::consts::PI;
+
+use crate::RealBuffer;
+
+/// A window sequence, stored as real samples ready to multiply against a
+/// signal or an ideal impulse response.
+#[derive(Clone, Debug)]
+pub struct Window {
+    pub samples: RealBuffer,
+}
+
+impl Window {
+    /// Builds a window from pre-computed samples.
+    pub fn new(samples: RealBuffer) -> Window {
+        Window { samples }
+    }
+
+    /// Number of samples in the window.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns `true` if the window has no samples.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+/// A triangular (Bartlett) window of `size` samples ramping linearly from
+/// `lower` at the edges up to `upper` at the center.
+pub fn triangular(size: usize, lower: f32, upper: f32) -> Window {
+    let mut samples = vec![0.0; size];
+    let peak = (size - 1) as f32 / 2.0;
+    for (n, s) in samples.iter_mut().enumerate() {
+        let dist = (n as f32 - peak).abs() / peak;
+        *s = upper - (upper - lower) * dist;
+    }
+    Window::new(samples)
+}
+
+/// A Hann window: `w[n] = 0.5 - 0.5*cos(2*PI*n/(N-1))`.
+pub fn hann(size: usize) -> Window {
+    let n1 = (size - 1) as f32;
+    let samples = (0..size)
+        .map(|n| 0.5 - 0.5 * (2.0 * PI * n as f32 / n1).cos())
+        .collect();
+    Window::new(samples)
+}
+
+/// A Hamming window: `w[n] = 0.54 - 0.46*cos(2*PI*n/(N-1))`.
+pub fn hamming(size: usize) -> Window {
+    let n1 = (size - 1) as f32;
+    let samples = (0..size)
+        .map(|n| 0.54 - 0.46 * (2.0 * PI * n as f32 / n1).cos())
+        .collect();
+    Window::new(samples)
+}
+
+/// A Blackman window:
+/// `w[n] = 0.42 - 0.5*cos(2*PI*n/(N-1)) + 0.08*cos(4*PI*n/(N-1))`.
+pub fn blackman(size: usize) -> Window {
+    let n1 = (size - 1) as f32;
+    let samples = (0..size)
+        .map(|n| {
+            let x = 2.0 * PI * n as f32 / n1;
+            0.42 - 0.5 * x.cos() + 0.08 * (2.0 * x).cos()
+        })
+        .collect();
+    Window::new(samples)
+}
+
+/// A Kaiser window with shape parameter `beta`, using the zeroth-order
+/// modified Bessel function of the first kind.
+pub fn kaiser(size: usize, beta: f32) -> Window {
+    let n1 = (size - 1) as f32;
+    let denom = bessel_i0(beta);
+    let samples = (0..size)
+        .map(|n| {
+            let r = 2.0 * n as f32 / n1 - 1.0;
+            bessel_i0(beta * (1.0 - r * r).sqrt()) / denom
+        })
+        .collect();
+    Window::new(samples)
+}
+
+/// Series approximation of the zeroth-order modified Bessel function
+/// `I0(x)`, used by the Kaiser window.
+fn bessel_i0(x: f32) -> f32 {
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let half_x = x / 2.0;
+    for k in 1..20 {
+        term *= (half_x / k as f32) * (half_x / k as f32);
+        sum += term;
+    }
+    sum
+}